@@ -1,21 +1,70 @@
 use anyhow::{Context, Result};
-use azure_security_keyvault::SecretClient;
+use futures::future::try_join_all;
+use serde::de::DeserializeOwned;
 use std::collections::HashMap;
 
+mod postgres;
+mod providers;
+mod reload;
+
+pub use postgres::{
+    build_dsn, build_dsn_with_access_token, fetch_postgres_access_token, POSTGRES_AAD_SCOPE,
+};
+pub use providers::{AzureKeyVaultProvider, EnvProvider, HashiCorpVaultProvider, SecretProvider};
+pub use reload::ReloadableVault;
+
 pub struct Vault {
     pub secrets: HashMap<String, String>,
 }
 
 pub trait VaultStorage {
     fn get_required(&self, key: &str) -> Result<String>;
+
+    /// Looks up `key` and deserializes its value as JSON into `T`.
+    ///
+    /// Azure Key Vault often stores compound values (connection blobs,
+    /// cert+key pairs, JSON credential objects) under a single secret name;
+    /// this saves callers from parsing the string by hand.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rusty_psql::{AzureKeyVaultProvider, Vault, VaultStorage};
+    /// use anyhow::Result;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Credentials {
+    ///     username: String,
+    ///     password: String,
+    /// }
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let secret_keys = vec!["".to_string()];
+    ///     let provider = AzureKeyVaultProvider::new("AZURE_KEY_VAULT_TEST")?;
+    ///     let vault = Vault::new(provider, secret_keys).await?;
+    ///     let creds: Credentials = vault.get_required_as("")?;
+    ///     Ok(())
+    /// }
+    /// ```
+    fn get_required_as<T: DeserializeOwned>(&self, key: &str) -> Result<T> {
+        let value = self.get_required(key)?;
+        serde_json::from_str(&value)
+            .with_context(|| format!("Failed to deserialize secret '{key}' as JSON"))
+    }
 }
 
 impl Vault {
-    /// Creates a Vault instance with Azure Key Vault secrets.
+    /// Creates a Vault instance backed by `provider`, eagerly fetching every
+    /// key in `db_keys`.
     ///
     /// # Arguments
     ///
-    /// * `url` - URL
+    /// * `provider` - The backend to fetch secrets from, e.g.
+    ///   [`AzureKeyVaultProvider`], [`HashiCorpVaultProvider`] or
+    ///   [`EnvProvider`]
+    /// * `db_keys` - The keys to fetch and keep available via
+    ///   [`VaultStorage::get_required`]
     ///
     /// # Returns
     ///
@@ -25,29 +74,88 @@ impl Vault {
     /// # Example
     ///
     /// ```
-    /// use rusty_psql::Vault;
+    /// use rusty_psql::{AzureKeyVaultProvider, Vault};
     /// use anyhow::Result;
     ///
     /// async fn example() -> Result<()> {
     ///     let secret_keys = vec!["".to_string()];
-    ///     let vault = Vault::new("AZURE_KEY_VAULT_TEST", secret_keys).await?;
+    ///     let provider = AzureKeyVaultProvider::new("AZURE_KEY_VAULT_TEST")?;
+    ///     let vault = Vault::new(provider, secret_keys).await?;
     ///     Ok(())
     /// }
     /// ```
-    pub async fn new(url: &str, db_keys: Vec<String>) -> Result<Self> {
-        let mut secrets = HashMap::new();
+    pub async fn new(provider: impl SecretProvider, db_keys: Vec<String>) -> Result<Self> {
+        let secrets = fetch_all(&provider, &db_keys).await?;
 
-        let credential =
-            azure_identity::create_credential().context("Failed to create credentials")?;
-        let client = SecretClient::new(url, credential)
-            .context("Failed to create a SecretClient instance")?;
+        Ok(Self { secrets })
+    }
 
-        let keys_iter = db_keys.iter();
-        for key in keys_iter {
-            secrets.insert(key.clone(), get_secret(&client, key.clone()).await?);
-        }
+    /// Builds a Vault directly from an in-memory map, without talking to any
+    /// backend.
+    ///
+    /// Useful for unit tests and no-network local dev, where callers want to
+    /// inject fixed secrets instead of constructing a real
+    /// [`SecretProvider`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rusty_psql::{Vault, VaultStorage};
+    /// use std::collections::HashMap;
+    ///
+    /// let secrets = HashMap::from([("db-pwd".to_string(), "hunter2".to_string())]);
+    /// let vault = Vault::from_map(secrets);
+    /// assert_eq!(vault.get_required("db-pwd").unwrap(), "hunter2");
+    /// ```
+    pub fn from_map(secrets: HashMap<String, String>) -> Self {
+        Self { secrets }
+    }
+}
 
-        Ok(Self { secrets })
+/// Fetches every key in `db_keys` from `provider` concurrently, failing the
+/// whole batch (with the offending key named) if any single fetch fails.
+pub(crate) async fn fetch_all<P: SecretProvider>(
+    provider: &P,
+    db_keys: &[String],
+) -> Result<HashMap<String, String>> {
+    let fetches = db_keys.iter().map(|key| async move {
+        let value = provider
+            .fetch(key)
+            .await
+            .with_context(|| format!("Failed to fetch secret '{key}'"))?;
+        Ok::<_, anyhow::Error>((key.clone(), value))
+    });
+
+    Ok(try_join_all(fetches).await?.into_iter().collect())
+}
+
+impl Vault {
+    /// Builds a vault that re-fetches every key in `db_keys` from `provider`
+    /// whenever the process receives `SIGUSR1`, and publishes each fresh
+    /// snapshot for [`ReloadableVault::get_required`] to observe.
+    ///
+    /// If a reload fails, a warning is logged and the previous snapshot
+    /// keeps serving callers rather than being torn down.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rusty_psql::{AzureKeyVaultProvider, Vault, VaultStorage};
+    /// use anyhow::Result;
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let secret_keys = vec!["".to_string()];
+    ///     let provider = AzureKeyVaultProvider::new("AZURE_KEY_VAULT_TEST")?;
+    ///     let vault = Vault::watch(provider, secret_keys).await?;
+    ///     let secret_key = vault.get_required("")?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn watch<P>(provider: P, db_keys: Vec<String>) -> Result<ReloadableVault>
+    where
+        P: SecretProvider + Send + Sync + 'static,
+    {
+        ReloadableVault::new(provider, db_keys).await
     }
 }
 
@@ -57,7 +165,7 @@ impl VaultStorage for Vault {
     /// # Arguments
     ///
     /// * `self` - Vault
-    /// * `key` - The Azure Key Vault secret
+    /// * `key` - The secret key
     ///
     /// # Returns
     ///
@@ -67,12 +175,13 @@ impl VaultStorage for Vault {
     /// # Example
     ///
     /// ```
-    /// use rusty_psql::{Vault, VaultStorage};
+    /// use rusty_psql::{AzureKeyVaultProvider, Vault, VaultStorage};
     /// use anyhow::Result;
     ///
     /// async fn example() -> Result<()> {
     ///     let secret_keys = vec!["".to_string()];
-    ///     let vault = Vault::new("AZURE_KEY_VAULT_TEST", secret_keys).await?;
+    ///     let provider = AzureKeyVaultProvider::new("AZURE_KEY_VAULT_TEST")?;
+    ///     let vault = Vault::new(provider, secret_keys).await?;
     ///     let secret_key = VaultStorage::get_required(&vault, "")?;
     ///     Ok(())
     /// }
@@ -85,14 +194,57 @@ impl VaultStorage for Vault {
     }
 }
 
-async fn get_secret(client: &SecretClient, key: String) -> Result<String> {
-    let response = client.get(key).await.context("Unable to retrieve value")?;
-    Ok(response.value.to_string())
-}
-
 #[cfg(test)]
 mod tests {
+    use super::{Vault, VaultStorage};
     use db_config::DatabaseConfig;
+    use serde::Deserialize;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_get_required_returns_value_for_known_key() {
+        let vault = Vault::from_map(HashMap::from([("db-pwd".to_string(), "hunter2".to_string())]));
+        assert_eq!(vault.get_required("db-pwd").unwrap(), "hunter2");
+    }
+
+    #[test]
+    fn test_get_required_errors_for_unknown_key() {
+        let vault = Vault::from_map(HashMap::new());
+        assert!(vault.get_required("missing").is_err());
+    }
+
+    #[derive(Deserialize, PartialEq, Debug)]
+    struct Credentials {
+        username: String,
+        password: String,
+    }
+
+    #[test]
+    fn test_get_required_as_deserializes_json_value() {
+        let vault = Vault::from_map(HashMap::from([(
+            "db-creds".to_string(),
+            r#"{"username":"app","password":"hunter2"}"#.to_string(),
+        )]));
+
+        let creds: Credentials = vault.get_required_as("db-creds").unwrap();
+        assert_eq!(
+            creds,
+            Credentials {
+                username: "app".to_string(),
+                password: "hunter2".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_get_required_as_errors_for_invalid_json() {
+        let vault = Vault::from_map(HashMap::from([(
+            "db-creds".to_string(),
+            "not json".to_string(),
+        )]));
+
+        assert!(vault.get_required_as::<Credentials>("db-creds").is_err());
+    }
 
     #[test]
     fn test_keys_returns_correct_number_of_fields() {
@@ -0,0 +1,41 @@
+use super::SecretProvider;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use azure_security_keyvault::SecretClient;
+
+/// Fetches secrets from an Azure Key Vault instance.
+///
+/// This is the original (and still default) backend: `url` is the vault's
+/// DNS name, and credentials are resolved the same way they always were, via
+/// `azure_identity::create_credential`.
+pub struct AzureKeyVaultProvider {
+    client: SecretClient,
+}
+
+impl AzureKeyVaultProvider {
+    /// Creates a provider backed by the Azure Key Vault at `url`.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - URL
+    pub fn new(url: &str) -> Result<Self> {
+        let credential =
+            azure_identity::create_credential().context("Failed to create credentials")?;
+        let client = SecretClient::new(url, credential)
+            .context("Failed to create a SecretClient instance")?;
+
+        Ok(Self { client })
+    }
+}
+
+#[async_trait]
+impl SecretProvider for AzureKeyVaultProvider {
+    async fn fetch(&self, key: &str) -> Result<String> {
+        let response = self
+            .client
+            .get(key)
+            .await
+            .context("Unable to retrieve value")?;
+        Ok(response.value.to_string())
+    }
+}
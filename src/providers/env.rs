@@ -0,0 +1,48 @@
+use super::SecretProvider;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use std::env;
+
+/// Reads secrets straight from environment variables.
+///
+/// A secret named `db-password` is looked up as `DB_PASSWORD` (the key is
+/// upper-cased and `-` is replaced with `_`). Handy for local development
+/// where there's no vault at all.
+pub struct EnvProvider;
+
+#[async_trait]
+impl SecretProvider for EnvProvider {
+    async fn fetch(&self, key: &str) -> Result<String> {
+        let var_name = key.to_uppercase().replace('-', "_");
+        env::var(&var_name).with_context(|| format!("Environment variable '{var_name}' not set"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EnvProvider;
+    use crate::SecretProvider;
+    use std::env;
+
+    #[tokio::test]
+    async fn test_fetch_uppercases_key_and_replaces_dashes() {
+        env::set_var("ENVPROVIDER_TEST_DB_PASSWORD", "hunter2");
+
+        let value = EnvProvider
+            .fetch("envprovider-test-db-password")
+            .await
+            .unwrap();
+
+        assert_eq!(value, "hunter2");
+        env::remove_var("ENVPROVIDER_TEST_DB_PASSWORD");
+    }
+
+    #[tokio::test]
+    async fn test_fetch_errors_when_env_var_missing() {
+        env::remove_var("ENVPROVIDER_TEST_MISSING");
+
+        let result = EnvProvider.fetch("envprovider-test-missing").await;
+
+        assert!(result.is_err());
+    }
+}
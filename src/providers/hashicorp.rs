@@ -0,0 +1,171 @@
+use super::SecretProvider;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::env;
+use std::path::PathBuf;
+use tokio::sync::OnceCell;
+
+/// Fetches secrets from a HashiCorp Vault KV v2 mount.
+///
+/// Issues `GET {addr}/v1/{mount}/data/{path}` with an `X-Vault-Token`
+/// header and reads the requested key out of the nested
+/// `{ "data": { "data": { <key>: <value> } } }` response body.
+///
+/// The whole document is a single HTTP round trip no matter how many keys
+/// are read out of it: the first call to [`fetch`](Self::fetch) populates a
+/// `OnceCell`, and every subsequent call (including the concurrent ones
+/// `Vault::new` fires off for each key) reads from that cached copy instead
+/// of re-requesting the same path.
+pub struct HashiCorpVaultProvider {
+    addr: String,
+    mount: String,
+    path: String,
+    token: String,
+    client: reqwest::Client,
+    document: OnceCell<HashMap<String, String>>,
+}
+
+impl HashiCorpVaultProvider {
+    /// Creates a provider for the secret at `mount`/`path` on the Vault
+    /// server reachable at `addr` (typically `$VAULT_ADDR`).
+    ///
+    /// The token is resolved from the `VAULT_TOKEN` environment variable,
+    /// falling back to the contents of `~/.vault-token`.
+    pub fn new(
+        addr: impl Into<String>,
+        mount: impl Into<String>,
+        path: impl Into<String>,
+    ) -> Result<Self> {
+        Ok(Self {
+            addr: addr.into(),
+            mount: mount.into(),
+            path: path.into(),
+            token: resolve_token()?,
+            client: reqwest::Client::new(),
+            document: OnceCell::new(),
+        })
+    }
+
+    async fn document(&self) -> Result<&HashMap<String, String>> {
+        self.document
+            .get_or_try_init(|| async {
+                let url = format!("{}/v1/{}/data/{}", self.addr, self.mount, self.path);
+
+                let response = self
+                    .client
+                    .get(&url)
+                    .header("X-Vault-Token", &self.token)
+                    .send()
+                    .await
+                    .with_context(|| format!("Unable to reach HashiCorp Vault at {url}"))?
+                    .error_for_status()
+                    .with_context(|| format!("HashiCorp Vault returned an error for {url}"))?
+                    .json::<KvV2Response>()
+                    .await
+                    .context("Unable to parse HashiCorp Vault KV v2 response")?;
+
+                Ok(response.data.data)
+            })
+            .await
+    }
+}
+
+fn resolve_token() -> Result<String> {
+    if let Ok(token) = env::var("VAULT_TOKEN") {
+        return Ok(token);
+    }
+
+    std::fs::read_to_string(vault_token_file())
+        .map(|token| token.trim().to_string())
+        .context("Neither VAULT_TOKEN nor ~/.vault-token provided a Vault token")
+}
+
+fn vault_token_file() -> PathBuf {
+    PathBuf::from(env::var("HOME").unwrap_or_default()).join(".vault-token")
+}
+
+#[derive(serde::Deserialize)]
+struct KvV2Response {
+    data: KvV2Data,
+}
+
+#[derive(serde::Deserialize)]
+struct KvV2Data {
+    data: HashMap<String, String>,
+}
+
+#[async_trait]
+impl SecretProvider for HashiCorpVaultProvider {
+    async fn fetch(&self, key: &str) -> Result<String> {
+        self.document()
+            .await?
+            .get(key)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("Secret '{}' not found at {}/{}", key, self.mount, self.path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HashiCorpVaultProvider;
+    use crate::SecretProvider;
+    use futures::future::try_join_all;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Spins up a tiny HTTP server that always answers a KV v2 document and
+    /// counts how many connections it accepted, so tests can assert exactly
+    /// how many round trips `HashiCorpVaultProvider` made.
+    async fn spawn_counting_kv_server() -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let hits = Arc::new(AtomicUsize::new(0));
+        let accepted = hits.clone();
+
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                accepted.fetch_add(1, Ordering::SeqCst);
+
+                tokio::spawn(async move {
+                    let mut buf = [0u8; 1024];
+                    let _ = socket.read(&mut buf).await;
+
+                    let body = r#"{"data":{"data":{"db-user":"app","db-pwd":"hunter2"}}}"#;
+                    let response = format!(
+                        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        body.len(),
+                        body
+                    );
+                    let _ = socket.write_all(response.as_bytes()).await;
+                });
+            }
+        });
+
+        (format!("http://{addr}"), hits)
+    }
+
+    #[tokio::test]
+    async fn test_fetch_caches_document_across_concurrent_calls() {
+        let (addr, hits) = spawn_counting_kv_server().await;
+        std::env::set_var("VAULT_TOKEN", "test-token");
+
+        let provider = HashiCorpVaultProvider::new(addr, "secret", "app").unwrap();
+        let results = try_join_all(
+            ["db-user", "db-pwd"]
+                .into_iter()
+                .map(|key| provider.fetch(key)),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(results, vec!["app".to_string(), "hunter2".to_string()]);
+        assert_eq!(
+            hits.load(Ordering::SeqCst),
+            1,
+            "expected a single HTTP round trip for both keys"
+        );
+    }
+}
@@ -0,0 +1,23 @@
+//! Pluggable secret backends.
+//!
+//! `Vault` (see [`crate::Vault`]) is generic over anything that implements
+//! [`SecretProvider`], so the same application can target Azure Key Vault in
+//! production and HashiCorp Vault or plain environment variables locally.
+
+mod azure;
+mod env;
+mod hashicorp;
+
+pub use azure::AzureKeyVaultProvider;
+pub use env::EnvProvider;
+pub use hashicorp::HashiCorpVaultProvider;
+
+use anyhow::Result;
+use async_trait::async_trait;
+
+/// A backend capable of fetching a single secret value by key.
+#[async_trait]
+pub trait SecretProvider: Send + Sync {
+    /// Fetches the current value of `key` from the backend.
+    async fn fetch(&self, key: &str) -> Result<String>;
+}
@@ -0,0 +1,65 @@
+use crate::{fetch_all, SecretProvider, VaultStorage};
+use anyhow::Result;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use tokio::signal::unix::{signal, SignalKind};
+
+/// A [`crate::Vault`] that transparently picks up rotated secrets.
+///
+/// Built via [`crate::Vault::watch`], it holds its secrets behind an
+/// `Arc<RwLock<_>>` snapshot that a background task swaps out every time the
+/// process receives `SIGUSR1`.
+pub struct ReloadableVault {
+    secrets: Arc<RwLock<HashMap<String, String>>>,
+}
+
+impl ReloadableVault {
+    pub(crate) async fn new<P>(provider: P, db_keys: Vec<String>) -> Result<Self>
+    where
+        P: SecretProvider + Send + Sync + 'static,
+    {
+        let provider = Arc::new(provider);
+        let secrets = Arc::new(RwLock::new(fetch_all(&*provider, &db_keys).await?));
+
+        spawn_reload_task(provider, db_keys, secrets.clone());
+
+        Ok(Self { secrets })
+    }
+}
+
+fn spawn_reload_task<P>(provider: Arc<P>, db_keys: Vec<String>, secrets: Arc<RwLock<HashMap<String, String>>>)
+where
+    P: SecretProvider + Send + Sync + 'static,
+{
+    tokio::spawn(async move {
+        let mut sigusr1 = match signal(SignalKind::user_defined1()) {
+            Ok(sigusr1) => sigusr1,
+            Err(err) => {
+                eprintln!("warning: failed to install SIGUSR1 handler, secret reload disabled: {err}");
+                return;
+            }
+        };
+
+        while sigusr1.recv().await.is_some() {
+            match fetch_all(&*provider, &db_keys).await {
+                Ok(fresh) => {
+                    *secrets.write().expect("secrets lock poisoned") = fresh;
+                }
+                Err(err) => {
+                    eprintln!("warning: secret reload failed, keeping previous snapshot: {err}");
+                }
+            }
+        }
+    });
+}
+
+impl VaultStorage for ReloadableVault {
+    fn get_required(&self, key: &str) -> Result<String> {
+        self.secrets
+            .read()
+            .expect("secrets lock poisoned")
+            .get(key)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("Required key '{}' not found", key))
+    }
+}